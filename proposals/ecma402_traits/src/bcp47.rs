@@ -0,0 +1,268 @@
+//! Parses [BCP 47](https://tools.ietf.org/html/bcp47) language tags, the reverse direction of
+//! [crate::AsBCP47].
+//!
+//! Parsing happens at one of three conformance levels, mirroring the distinction `icu_locid`
+//! draws between well-formed, valid and canonical identifiers:
+//!
+//! * *well-formed*: the tag matches the BCP 47 grammar.  This is the only level actually
+//!   enforced by [parse_language_identifier] below; it requires no registry data.
+//! * *valid*: well-formed, and every subtag is drawn from the registered IANA subtag sets.
+//! * *canonical*: valid, and any deprecated subtag has been replaced by its preferred value.
+//!
+//! The latter two require the IANA Language Subtag Registry, which this crate does not vendor,
+//! so [Conformance::Valid] and [Conformance::Canonical] are accepted by
+//! [parse_language_identifier] but currently only get a well-formed check; a real implementation
+//! would consult registry data fetched from a [crate::Locale]-agnostic source.
+
+use crate::LanguageIdentifier;
+use crate::Variants;
+use std::fmt;
+
+/// How strictly a tag was (or should be) checked against the IANA registry.  See the [module
+/// docs](self) for what each level means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Conformance {
+    WellFormed,
+    Valid,
+    Canonical,
+}
+
+/// Which part of the tag a [ParseError] was raised for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubtagKind {
+    Language,
+    Script,
+    Region,
+    Variant,
+}
+
+impl fmt::Display for SubtagKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SubtagKind::Language => "language",
+            SubtagKind::Script => "script",
+            SubtagKind::Region => "region",
+            SubtagKind::Variant => "variant",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Returned when a tag passed to [parse_language_identifier] does not parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The kind of subtag that failed to parse.
+    pub subtag: SubtagKind,
+    /// The offending subtag, as found in the input.
+    pub value: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {} subtag: {:?}", self.subtag, self.value)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The result of parsing a BCP 47 tag: a language identifier with its components already
+/// normalized (language lowercase, script title case, region uppercase, variants lowercase).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedLanguageIdentifier {
+    language: String,
+    script: Option<String>,
+    region: Option<String>,
+    variants: Vec<String>,
+}
+
+impl LanguageIdentifier for ParsedLanguageIdentifier {
+    fn language(&self) -> &str {
+        &self.language
+    }
+    fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+    fn script(&self) -> Option<&str> {
+        self.script.as_deref()
+    }
+}
+
+impl Variants for ParsedLanguageIdentifier {
+    type Iter<'a> = std::iter::Map<std::slice::Iter<'a, String>, fn(&'a String) -> &'a str>;
+
+    fn num_variants(&self) -> usize {
+        self.variants.len()
+    }
+
+    fn variants(&self) -> Self::Iter<'_> {
+        self.variants.iter().map(|s| s.as_str())
+    }
+}
+
+/// Allows constructing `Self` from a BCP 47 tag, the reverse of [crate::AsBCP47].
+pub trait FromBcp47: Sized {
+    type Err;
+
+    /// Parses `tag`, at [Conformance::WellFormed].
+    fn from_bcp47(tag: &str) -> Result<Self, Self::Err>;
+}
+
+impl FromBcp47 for ParsedLanguageIdentifier {
+    type Err = ParseError;
+
+    fn from_bcp47(tag: &str) -> Result<Self, Self::Err> {
+        parse_language_identifier(tag, Conformance::WellFormed)
+    }
+}
+
+fn is_ascii_alpha(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_ascii_alphanumeric(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_ascii_digit(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_variant(s: &str) -> bool {
+    (s.len() >= 5 && s.len() <= 8 && is_ascii_alphanumeric(s))
+        || (s.len() == 4 && s.as_bytes()[0].is_ascii_digit() && is_ascii_alphanumeric(&s[1..]))
+}
+
+fn title_case(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => {
+            first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()
+        }
+        None => String::new(),
+    }
+}
+
+/// Parses `tag` into its language, script, region and variant subtags, normalizing case along
+/// the way.  See the [module docs](self) for the meaning of `level`.
+pub fn parse_language_identifier(
+    tag: &str,
+    level: Conformance,
+) -> Result<ParsedLanguageIdentifier, ParseError> {
+    // Only well-formed checks are implemented; `level` is accepted so callers can opt into
+    // stricter checks once this crate gains registry data.  See the module docs.
+    let _ = level;
+
+    let mut subtags = tag.split(['-', '_']);
+
+    let language = subtags.next().unwrap_or("");
+    if language != "und"
+        && !((language.len() == 2 || language.len() == 3 || (5..=8).contains(&language.len()))
+            && is_ascii_alpha(language))
+    {
+        return Err(ParseError {
+            subtag: SubtagKind::Language,
+            value: language.to_string(),
+        });
+    }
+    let language = language.to_ascii_lowercase();
+
+    let mut next = subtags.next();
+
+    let mut script = None;
+    if let Some(s) = next.filter(|s| s.len() == 4 && is_ascii_alpha(s)) {
+        script = Some(title_case(s));
+        next = subtags.next();
+    }
+
+    let mut region = None;
+    if let Some(s) =
+        next.filter(|s| (s.len() == 2 && is_ascii_alpha(s)) || (s.len() == 3 && is_ascii_digit(s)))
+    {
+        region = Some(s.to_ascii_uppercase());
+        next = subtags.next();
+    }
+
+    let mut variants = Vec::new();
+    while let Some(s) = next {
+        // A single-letter subtag marks the start of an extension (`-u-`, `-t-`, `-x-`, ...)
+        // rather than a variant; this parser doesn't model extensions, so stop here.
+        if s.len() == 1 {
+            break;
+        }
+        if !is_variant(s) {
+            return Err(ParseError {
+                subtag: SubtagKind::Variant,
+                value: s.to_string(),
+            });
+        }
+        variants.push(s.to_ascii_lowercase());
+        next = subtags.next();
+    }
+
+    Ok(ParsedLanguageIdentifier {
+        language,
+        script,
+        region,
+        variants,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_full_tag() {
+        let id = parse_language_identifier("en-Latn-US-fonipa", Conformance::WellFormed).unwrap();
+        assert_eq!(id.language(), "en");
+        assert_eq!(id.script(), Some("Latn"));
+        assert_eq!(id.region(), Some("US"));
+        assert_eq!(id.num_variants(), 1);
+        let variants: Vec<&str> = id.variants().collect();
+        assert_eq!(variants, vec!["fonipa"]);
+    }
+
+    #[test]
+    fn normalizes_case() {
+        let id = parse_language_identifier("EN-latn-us", Conformance::WellFormed).unwrap();
+        assert_eq!(id.language(), "en");
+        assert_eq!(id.script(), Some("Latn"));
+        assert_eq!(id.region(), Some("US"));
+    }
+
+    #[test]
+    fn accepts_und() {
+        let id = parse_language_identifier("und", Conformance::WellFormed).unwrap();
+        assert_eq!(id.language(), "und");
+        assert_eq!(id.region(), None);
+        assert_eq!(id.script(), None);
+    }
+
+    #[test]
+    fn rejects_bad_language() {
+        let err = parse_language_identifier("e-US", Conformance::WellFormed).unwrap_err();
+        assert_eq!(err.subtag, SubtagKind::Language);
+    }
+
+    #[test]
+    fn rejects_bad_variant() {
+        let err = parse_language_identifier("en-US-ab", Conformance::WellFormed).unwrap_err();
+        assert_eq!(err.subtag, SubtagKind::Variant);
+    }
+
+    #[test]
+    fn stops_before_extensions() {
+        let id = parse_language_identifier("th-TH-u-ca-buddhist-nu-thai", Conformance::WellFormed)
+            .unwrap();
+        assert_eq!(id.language(), "th");
+        assert_eq!(id.region(), Some("TH"));
+        assert_eq!(id.num_variants(), 0);
+    }
+
+    #[test]
+    fn from_bcp47_parses_tag() {
+        let id = ParsedLanguageIdentifier::from_bcp47("th-TH").unwrap();
+        assert_eq!(id.language(), "th");
+        assert_eq!(id.region(), Some("TH"));
+    }
+}
@@ -0,0 +1,64 @@
+//! Implements ECMA-402 `Intl.Collator`.
+
+use crate::Locale;
+use crate::data_provider::DataProvider;
+use std::cmp::Ordering;
+
+/// Options for constructing a [Collator].
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    // Left empty for now: sensitivity, numeric collation and friends would live here, mirroring
+    // `Intl.CollatorOptions`.
+}
+
+/// Implements ECMA-402 `Intl.Collator`.
+pub trait Collator<L>: Sized
+where
+    L: Locale,
+{
+    type Error;
+
+    /// Constructor method.
+    fn try_new<P: DataProvider>(
+        provider: &P,
+        locale: L,
+        options: Options,
+    ) -> Result<Self, Self::Error>;
+
+    /// Compares `a` and `b` according to the locale and options used to construct `self`.
+    fn compare(&self, a: &str, b: &str) -> Ordering;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{TestLocale, TestProvider};
+
+    /// Compares case-insensitively, ignoring locale and options.
+    struct CaseInsensitiveCollator;
+
+    impl Collator<TestLocale> for CaseInsensitiveCollator {
+        type Error = ();
+
+        fn try_new<P: DataProvider>(
+            _provider: &P,
+            _locale: TestLocale,
+            _options: Options,
+        ) -> Result<Self, Self::Error> {
+            Ok(CaseInsensitiveCollator)
+        }
+
+        fn compare(&self, a: &str, b: &str) -> Ordering {
+            a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+        }
+    }
+
+    #[test]
+    fn compares_case_insensitively() {
+        let collator =
+            CaseInsensitiveCollator::try_new(&TestProvider, TestLocale, Options::default())
+                .unwrap();
+        assert_eq!(collator.compare("Apple", "apple"), Ordering::Equal);
+        assert_eq!(collator.compare("apple", "banana"), Ordering::Less);
+    }
+}
@@ -0,0 +1,115 @@
+//! A pluggable source of CLDR-derived data for the ECMA-402 services in this crate.
+//!
+//! ECMA-402 services need CLDR-derived data to do their formatting, but nothing about them
+//! (prior to this module) said where that data should come from.  [DataProvider] is the seam:
+//! every service's `try_new` takes a `&impl DataProvider` alongside the locale and options, so
+//! a single binary can swap in a compiled-in, FFI, or downloaded data source without touching
+//! any call site.  This mirrors how Boa wired an ICU4X data provider into its `Intl`
+//! implementation.
+
+use crate::Locale;
+
+/// Identifies which service's data is being requested from a [DataProvider].  Implementations
+/// are expected to grow a variant here whenever this crate gains a new service module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Service {
+    NumberFormat,
+    DateTimeFormat,
+    PluralRules,
+    ListFormat,
+    Collator,
+    RelativeTimeFormat,
+    DisplayNames,
+}
+
+/// Reports that a [DataProvider] has no data for the requested `(service, locale)` pair.  This
+/// is kept distinct from [DataProvider]'s own `Error` type so that callers can fall back (e.g.
+/// to a parent locale, or `und`) instead of treating it as a hard failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataUnavailable {
+    pub service: Service,
+}
+
+/// Either [DataUnavailable], or some other provider-specific failure (I/O, malformed data, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadError<E> {
+    Unavailable(DataUnavailable),
+    Other(E),
+}
+
+/// Supplies the CLDR-derived data that ECMA-402 services need, keyed by `(service, locale)`.
+pub trait DataProvider {
+    /// The data payload type this provider produces.  A full implementation would likely make
+    /// this an enum over each [Service]'s data shape; this proposal leaves the exact
+    /// representation to the implementor.
+    type Payload;
+
+    /// A provider-specific error distinct from "no data for this locale".  See [LoadError].
+    type Error;
+
+    /// Loads the data payload needed to serve `service` for `locale`.
+    fn load<L: Locale>(
+        &self,
+        service: Service,
+        locale: &L,
+    ) -> Result<Self::Payload, LoadError<Self::Error>>;
+
+    /// Returns the BCP 47 tag of the host environment's current locale, if one can be
+    /// determined (e.g. from an OS locale API, analogous to the `sys_locale` crate).  The
+    /// default implementation reports that none is available.
+    fn default_locale(&self) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{TestLocale, TestProvider};
+
+    /// Always has a (trivial) payload, for any service or locale.
+    struct AlwaysAvailableProvider;
+
+    impl DataProvider for AlwaysAvailableProvider {
+        type Payload = &'static str;
+        type Error = ();
+
+        fn load<L: crate::Locale>(
+            &self,
+            _service: Service,
+            _locale: &L,
+        ) -> Result<Self::Payload, LoadError<Self::Error>> {
+            Ok("data")
+        }
+
+        fn default_locale(&self) -> Option<String> {
+            Some("en-US".to_string())
+        }
+    }
+
+    #[test]
+    fn loads_data_when_available() {
+        let payload = AlwaysAvailableProvider
+            .load(Service::NumberFormat, &TestLocale)
+            .unwrap();
+        assert_eq!(payload, "data");
+        assert_eq!(
+            AlwaysAvailableProvider.default_locale(),
+            Some("en-US".to_string())
+        );
+    }
+
+    #[test]
+    fn reports_unavailable_distinctly() {
+        let err = TestProvider
+            .load(Service::Collator, &TestLocale)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            LoadError::Unavailable(DataUnavailable {
+                service: Service::Collator
+            })
+        );
+        assert_eq!(TestProvider.default_locale(), None);
+    }
+}
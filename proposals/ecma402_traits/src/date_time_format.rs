@@ -0,0 +1,73 @@
+//! Implements ECMA-402 `Intl.DateTimeFormat`.
+
+use crate::Locale;
+use crate::data_provider::DataProvider;
+use std::fmt;
+
+/// Options for constructing a [DateTimeFormat].
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    // Left empty for now: date style, time style, calendar and time zone overrides and friends
+    // would live here, mirroring `Intl.DateTimeFormatOptions`.
+}
+
+/// Implements ECMA-402 `Intl.DateTimeFormat`.
+///
+/// Unlike [crate::number_format::NumberFormat], the value being formatted is not modeled here:
+/// this proposal leaves the choice of date/time representation (e.g. a `SystemTime`, or a
+/// third-party calendar type) up to the implementor.
+pub trait DateTimeFormat<L>: Sized
+where
+    L: Locale,
+{
+    type Error;
+
+    /// The date/time value type accepted by [Self::format].
+    type DateTime;
+
+    /// Constructor method.
+    fn try_new<P: DataProvider>(
+        provider: &P,
+        locale: L,
+        options: Options,
+    ) -> Result<Self, Self::Error>;
+
+    /// Formats `value` according to the locale and options used to construct `self`, writing
+    /// the result into `sink`.
+    fn format<W: fmt::Write>(&self, value: &Self::DateTime, sink: &mut W) -> fmt::Result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{TestLocale, TestProvider};
+
+    /// Formats a Unix timestamp (seconds since the epoch) as `"epoch:<seconds>"`, ignoring
+    /// locale and options.
+    struct EpochFormat;
+
+    impl DateTimeFormat<TestLocale> for EpochFormat {
+        type Error = ();
+        type DateTime = u64;
+
+        fn try_new<P: DataProvider>(
+            _provider: &P,
+            _locale: TestLocale,
+            _options: Options,
+        ) -> Result<Self, Self::Error> {
+            Ok(EpochFormat)
+        }
+
+        fn format<W: fmt::Write>(&self, value: &u64, sink: &mut W) -> fmt::Result {
+            write!(sink, "epoch:{value}")
+        }
+    }
+
+    #[test]
+    fn formats_value() {
+        let dtf = EpochFormat::try_new(&TestProvider, TestLocale, Options::default()).unwrap();
+        let mut out = String::new();
+        dtf.format(&1_600_000_000, &mut out).unwrap();
+        assert_eq!(out, "epoch:1600000000");
+    }
+}
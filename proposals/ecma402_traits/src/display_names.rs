@@ -0,0 +1,79 @@
+//! Implements ECMA-402 `Intl.DisplayNames`.
+
+use crate::Locale;
+use crate::data_provider::DataProvider;
+use std::fmt;
+
+/// The kind of code being translated to a display name.  See `Intl.DisplayNamesOptions.type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
+    Language,
+    Region,
+    Script,
+    Currency,
+}
+
+/// Options for constructing a [DisplayNames].
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub r#type: Type,
+}
+
+/// Implements ECMA-402 `Intl.DisplayNames`.
+pub trait DisplayNames<L>: Sized
+where
+    L: Locale,
+{
+    type Error;
+
+    /// Constructor method.
+    fn try_new<P: DataProvider>(
+        provider: &P,
+        locale: L,
+        options: Options,
+    ) -> Result<Self, Self::Error>;
+
+    /// Looks up the display name of `code` (e.g. a language, region, script or currency code,
+    /// depending on [Options::type]) in the locale used to construct `self`, writing the result
+    /// into `sink`.
+    fn of<W: fmt::Write>(&self, code: &str, sink: &mut W) -> fmt::Result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{TestLocale, TestProvider};
+
+    /// Knows the display name for exactly one region code, ignoring locale.
+    struct OneRegionDisplayNames;
+
+    impl DisplayNames<TestLocale> for OneRegionDisplayNames {
+        type Error = ();
+
+        fn try_new<P: DataProvider>(
+            _provider: &P,
+            _locale: TestLocale,
+            _options: Options,
+        ) -> Result<Self, Self::Error> {
+            Ok(OneRegionDisplayNames)
+        }
+
+        fn of<W: fmt::Write>(&self, code: &str, sink: &mut W) -> fmt::Result {
+            match code {
+                "US" => write!(sink, "United States"),
+                _ => write!(sink, "{code}"),
+            }
+        }
+    }
+
+    #[test]
+    fn looks_up_display_name() {
+        let options = Options {
+            r#type: Type::Region,
+        };
+        let dn = OneRegionDisplayNames::try_new(&TestProvider, TestLocale, options).unwrap();
+        let mut out = String::new();
+        dn.of("US", &mut out).unwrap();
+        assert_eq!(out, "United States");
+    }
+}
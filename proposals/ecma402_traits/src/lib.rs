@@ -33,17 +33,52 @@
 //!
 //! This proposal contains the following traits:
 //!
-//! * [AsBCP47]: A single-method trait for converting an object into a BCP 47 serialized form.
-//!   This is a minimum required to be able to define ECMA402 compatible APIs, which take arrays
-//!   of locales and friends.
+//! * [AsBCP47]: A trait for writing an object's BCP 47 serialized form into a sink.  This is a
+//!   minimum required to be able to define ECMA402 compatible APIs, which take arrays of locales
+//!   and friends.
 //! * [LanguageIdentifier]: Adds immutable getters for language identifier components.
+//! * [Locale]: The trait bound that every ECMA-402 service below is parameterized over.
+//!
+//! [AsBCP47] only goes one direction (object to string); [bcp47::FromBcp47] and
+//! [bcp47::parse_language_identifier] go the other way, turning a tag like `en-Latn-US-fonipa`
+//! back into its subtags.
+//!
+//! # Part 2: ECMA-402 formatting services
+//!
+//! Each of the following modules declares a single trait that mirrors one constructor of the
+//! [ECMA-402 `Intl` namespace](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl).
+//! They all follow the same constructor-plus-format shape: a
+//! `try_new(provider, locale, options)` constructor taking a [data_provider::DataProvider] for
+//! its CLDR-derived data, and a method that writes its result into a [std::fmt::Write] sink
+//! rather than allocating a `String`, so that implementations backed by ICU, Unic, or anything
+//! else can avoid intermediate allocations.
+//!
+//! * [number_format::NumberFormat]
+//! * [date_time_format::DateTimeFormat]
+//! * [plural_rules::PluralRules]
+//! * [list_format::ListFormat]
+//! * [collator::Collator]
+//! * [relative_time_format::RelativeTimeFormat]
+//! * [display_names::DisplayNames]
+
+use std::fmt;
+
+pub mod bcp47;
+pub mod collator;
+pub mod data_provider;
+pub mod date_time_format;
+pub mod display_names;
+pub mod list_format;
+pub mod number_format;
+pub mod plural_rules;
+pub mod relative_time_format;
 
 /// Represents an immutable language identifier.
 ///
 /// This trait can be passed into functions that are not expected to be able to mutate the
 /// identifier.  The `language` property must be defined, or equal to the literal string `und` if
-/// it is left unspecified.  Other properties are optional.  See [weird::Variants] for the
-/// obviously missing treatment of variants subtags.
+/// it is left unspecified.  Other properties are optional.  See [Variants] for variants subtag
+/// access.
 pub trait LanguageIdentifier {
     /// Returns the language subtag of the `language::Identifier`.  If the
     /// language subtag is empty, the returned value is `und`.
@@ -59,50 +94,116 @@ pub trait LanguageIdentifier {
 /// Allows representing the item (a locale object or a language identifier) in the form compatible
 /// with the [BCP 47 representation](https://tools.ietf.org/html/bcp47).
 pub trait AsBCP47 {
-    /// Returns a BCP 47 representation of the object.  This represents a canonical serialization
-    /// of all properties of a language identifier or a locale into a string.  Some objects, like
-    /// full-blown locales have extensions that are required to be serialized in a very specific
-    /// way.  Follow BCP 47 practices to do so when implementing this trait.
-    fn as_bcp47(&self) -> &str;
+    /// Writes a BCP 47 representation of the object into `sink`.  This represents a canonical
+    /// serialization of all properties of a language identifier or a locale into a string.  Some
+    /// objects, like full-blown locales, have extensions that are required to be serialized in a
+    /// very specific way.  Follow BCP 47 practices to do so when implementing this trait.
+    ///
+    /// Writing directly into a caller-supplied sink, rather than returning a pre-computed
+    /// `&str`, lets implementations whose canonical form differs from their stored form (e.g.
+    /// reordered extensions, case normalization, deprecated-subtag replacement) serialize lazily
+    /// instead of having to store the canonical string at construction time.
+    fn write_bcp47<W: fmt::Write>(&self, sink: &mut W) -> fmt::Result;
+
+    /// A hint for how many bytes [Self::write_bcp47] is expected to write, so that [Self::as_bcp47]
+    /// (or another caller managing its own buffer) can pre-size it and avoid reallocation.
+    /// Returning 0, the default, means no hint is available; it must not be read as a promise
+    /// about the actual output length.
+    fn write_len(&self) -> usize {
+        0
+    }
+
+    /// Returns a BCP 47 representation of the object, by writing it into a new `String`.
+    fn as_bcp47(&self) -> String {
+        let mut sink = String::with_capacity(self.write_len());
+        self.write_bcp47(&mut sink)
+            .expect("writing to a String cannot fail");
+        sink
+    }
 }
 
-/// Traits that ended up being unusual or weird because of issues unrelated to their structure.
-/// Specifically [weird::Variants] departs from what it should have been because of issues with
-/// defining a lifetime of an iterator.
-pub mod weird {
+/// A locale usable with the ECMA-402 formatting services in this crate.
+///
+/// This is a blanket trait: any type that can report its components ([LanguageIdentifier]) and
+/// serialize itself ([AsBCP47]) already qualifies.  Service traits like
+/// [number_format::NumberFormat] are generic over `L: Locale` so that a single locale type can be
+/// shared across all of them.
+pub trait Locale: LanguageIdentifier + AsBCP47 {}
 
-    /// Allows access to variants.  Variants are guaranteed to be valid.
-    ///
-    /// What I had wanted originally is something that returns an iterator; but it turns out that
-    /// it's quite involved to do so in rust today.  One would probably want to use an
-    /// [ExactSizeIterator] for this purpose, but it turns out that it is very involved to define
-    /// specifically a trait that establishes the lifetime relationships between the elements, the
-    /// iterator itself and the [Variants].  So I didn't, and instead provided the needed functions
-    /// here.  An `has_variants` predicate is absent because it's equivalent to
-    /// `num_variants()==0`, and calling `num_variants()` should not require counting.
-    pub trait Variants {
-        /// Returns an integer representing the number of variants defined in this language
-        /// identifier.
-        fn num_variants(&self) -> usize;
-
-        /// Calls `for_each` on each variant defined, and passes each one in turn
-        /// to it.  Iteration order is random.  An example use is given below.  Care
-        /// must be taken not to rely on any specific iteration order.
-        ///
-        /// ``` ignore
-        /// let mut variants = HashSet::new();
-        /// id.for_each_variant(|s| {
-        ///     variants.insert(s.to_string());
-        /// });
-        /// ```
-        fn for_each_variant(&self, for_each: impl FnMut(&str));
+impl<T> Locale for T where T: LanguageIdentifier + AsBCP47 {}
+
+/// Allows access to variants.  Variants are guaranteed to be valid.
+///
+/// An `has_variants` predicate is absent because it's equivalent to `num_variants()==0`, and
+/// calling `num_variants()` should not require counting.
+pub trait Variants {
+    /// The iterator type returned by [Self::variants].
+    type Iter<'a>: ExactSizeIterator<Item = &'a str>
+    where
+        Self: 'a;
+
+    /// Returns an integer representing the number of variants defined in this language
+    /// identifier.
+    fn num_variants(&self) -> usize;
+
+    /// Returns an iterator over the variants defined in this language identifier.  Iteration
+    /// order is unspecified.  Care must be taken not to rely on any specific iteration order.
+    fn variants(&self) -> Self::Iter<'_>;
+}
+
+/// Fixtures shared by the `mod tests` sections of the service trait modules
+/// ([number_format], [date_time_format], [plural_rules], [list_format], [collator],
+/// [relative_time_format], [display_names]), so each of them doesn't have to redeclare a toy
+/// [Locale] and [data_provider::DataProvider].
+#[cfg(test)]
+pub(crate) mod test_fixtures {
+    use crate::data_provider::{DataProvider, DataUnavailable, LoadError, Service};
+    use crate::{AsBCP47, LanguageIdentifier};
+    use std::fmt;
+
+    /// A fixed `en-US` locale, just enough to satisfy `L: Locale` in the service traits' tests.
+    pub(crate) struct TestLocale;
+
+    impl LanguageIdentifier for TestLocale {
+        fn language(&self) -> &str {
+            "en"
+        }
+        fn region(&self) -> Option<&str> {
+            Some("US")
+        }
+        fn script(&self) -> Option<&str> {
+            None
+        }
+    }
+
+    impl AsBCP47 for TestLocale {
+        fn write_bcp47<W: fmt::Write>(&self, sink: &mut W) -> fmt::Result {
+            write!(sink, "en-US")
+        }
+    }
+
+    /// A provider that never has data, since the toy service implementations under test don't
+    /// need real CLDR data to demonstrate the shape of `try_new`/the format methods.
+    pub(crate) struct TestProvider;
+
+    impl DataProvider for TestProvider {
+        type Payload = ();
+        type Error = ();
+
+        fn load<L: crate::Locale>(
+            &self,
+            service: Service,
+            _locale: &L,
+        ) -> Result<Self::Payload, LoadError<Self::Error>> {
+            Err(LoadError::Unavailable(DataUnavailable { service }))
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::weird::Variants;
     use crate::LanguageIdentifier;
+    use crate::Variants;
     use std::collections::HashSet;
 
     /// This is a sample implementation of the [Identifier] trait.  The static
@@ -126,13 +227,39 @@ mod tests {
         }
     }
 
+    /// Borrows each of [TestID]'s variants for the lifetime of the [TestID] borrow, rather than
+    /// `TestID`'s own `'static` one.
+    struct TestIDVariantsIter<'a> {
+        inner: std::slice::Iter<'a, &'static str>,
+    }
+
+    impl<'a> Iterator for TestIDVariantsIter<'a> {
+        type Item = &'a str;
+        fn next(&mut self) -> Option<Self::Item> {
+            self.inner.next().copied()
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<'a> ExactSizeIterator for TestIDVariantsIter<'a> {
+        fn len(&self) -> usize {
+            self.inner.len()
+        }
+    }
+
     impl Variants for TestID {
+        type Iter<'a> = TestIDVariantsIter<'a>;
+
         fn num_variants(&self) -> usize {
             self.variants.len()
         }
 
-        fn for_each_variant(&self, mut for_each: impl FnMut(&str)) {
-            self.variants.iter().map(|s| for_each(s)).for_each(drop);
+        fn variants(&self) -> Self::Iter<'_> {
+            TestIDVariantsIter {
+                inner: self.variants.iter(),
+            }
         }
     }
 
@@ -149,16 +276,11 @@ mod tests {
         assert_eq!(id.region(), Some("US"));
         assert_eq!(id.script(), None);
 
-        let mut variants = HashSet::new();
-        id.for_each_variant(|s| {
-            variants.insert(s.to_string());
-        });
+        assert_eq!(id.num_variants(), 2);
+        let variants: HashSet<&str> = id.variants().collect();
 
         // Iteration order is unspecified.
-        let expected: HashSet<String> = ["west_coast", "east_coast"]
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
+        let expected: HashSet<&str> = ["west_coast", "east_coast"].into_iter().collect();
         assert_eq!(variants, expected);
     }
 }
@@ -0,0 +1,121 @@
+//! Implements ECMA-402 `Intl.ListFormat`.
+
+use crate::Locale;
+use crate::data_provider::DataProvider;
+use std::fmt;
+
+/// The kind of list being joined.  See `Intl.ListFormatOptions.type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
+    /// E.g. "A, B, and C".
+    Conjunction,
+    /// E.g. "A, B, or C".
+    Disjunction,
+    /// E.g. "A, B, C" with no conjunction, used for units.
+    Unit,
+}
+
+/// How verbose the joined list should be.  See `Intl.ListFormatOptions.style`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Long,
+    Short,
+    Narrow,
+}
+
+/// Options for constructing a [ListFormat].
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub r#type: Type,
+    pub style: Style,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            r#type: Type::Conjunction,
+            style: Style::Long,
+        }
+    }
+}
+
+/// Implements ECMA-402 `Intl.ListFormat`.
+pub trait ListFormat<L>: Sized
+where
+    L: Locale,
+{
+    type Error;
+
+    /// Constructor method.
+    fn try_new<P: DataProvider>(
+        provider: &P,
+        locale: L,
+        options: Options,
+    ) -> Result<Self, Self::Error>;
+
+    /// Joins `items` according to the locale, type and style used to construct `self`, writing
+    /// the result into `sink`.
+    fn format<'a, W: fmt::Write>(
+        &self,
+        items: impl Iterator<Item = &'a str>,
+        sink: &mut W,
+    ) -> fmt::Result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{TestLocale, TestProvider};
+
+    /// Joins items with `self.r#type`'s conjunction/disjunction word (or nothing, for `Unit`),
+    /// ignoring locale and style.
+    struct EnglishListFormat {
+        r#type: Type,
+    }
+
+    impl ListFormat<TestLocale> for EnglishListFormat {
+        type Error = ();
+
+        fn try_new<P: DataProvider>(
+            _provider: &P,
+            _locale: TestLocale,
+            options: Options,
+        ) -> Result<Self, Self::Error> {
+            Ok(EnglishListFormat {
+                r#type: options.r#type,
+            })
+        }
+
+        fn format<'a, W: fmt::Write>(
+            &self,
+            items: impl Iterator<Item = &'a str>,
+            sink: &mut W,
+        ) -> fmt::Result {
+            let joiner = match self.r#type {
+                Type::Conjunction => ", and ",
+                Type::Disjunction => ", or ",
+                Type::Unit => ", ",
+            };
+            let mut items = items.peekable();
+            while let Some(item) = items.next() {
+                sink.write_str(item)?;
+                if items.peek().is_some() {
+                    sink.write_str(joiner)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn joins_items() {
+        let options = Options {
+            r#type: Type::Conjunction,
+            ..Options::default()
+        };
+        let lf = EnglishListFormat::try_new(&TestProvider, TestLocale, options).unwrap();
+        let mut out = String::new();
+        lf.format(["A", "B", "C"].into_iter(), &mut out).unwrap();
+        assert_eq!(out, "A, and B, and C");
+    }
+}
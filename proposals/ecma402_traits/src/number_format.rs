@@ -0,0 +1,67 @@
+//! Implements ECMA-402 `Intl.NumberFormat`.
+
+use crate::Locale;
+use crate::data_provider::DataProvider;
+use std::fmt;
+
+/// Options for constructing a [NumberFormat].
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    // Left empty for now: style, currency, minimum/maximum fraction digits and friends would
+    // live here, mirroring `Intl.NumberFormatOptions`.
+}
+
+/// Implements ECMA-402 `Intl.NumberFormat`.
+pub trait NumberFormat<L>: Sized
+where
+    L: Locale,
+{
+    type Error;
+
+    /// Constructor method.
+    fn try_new<P: DataProvider>(
+        provider: &P,
+        locale: L,
+        options: Options,
+    ) -> Result<Self, Self::Error>;
+
+    /// Formats `value` according to the locale and options used to construct `self`, writing
+    /// the result into `sink`.
+    fn format<W: fmt::Write>(&self, value: f64, sink: &mut W) -> fmt::Result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{TestLocale, TestProvider};
+
+    /// Formats `value` with a fixed number of fraction digits, ignoring locale and options.
+    struct FixedPrecisionFormat {
+        fraction_digits: usize,
+    }
+
+    impl NumberFormat<TestLocale> for FixedPrecisionFormat {
+        type Error = ();
+
+        fn try_new<P: DataProvider>(
+            _provider: &P,
+            _locale: TestLocale,
+            _options: Options,
+        ) -> Result<Self, Self::Error> {
+            Ok(FixedPrecisionFormat { fraction_digits: 2 })
+        }
+
+        fn format<W: fmt::Write>(&self, value: f64, sink: &mut W) -> fmt::Result {
+            write!(sink, "{value:.*}", self.fraction_digits)
+        }
+    }
+
+    #[test]
+    fn formats_value() {
+        let nf =
+            FixedPrecisionFormat::try_new(&TestProvider, TestLocale, Options::default()).unwrap();
+        let mut out = String::new();
+        nf.format(1234.5, &mut out).unwrap();
+        assert_eq!(out, "1234.50");
+    }
+}
@@ -0,0 +1,80 @@
+//! Implements ECMA-402 `Intl.PluralRules`.
+
+use crate::Locale;
+use crate::data_provider::DataProvider;
+
+/// The CLDR plural category a value was resolved to.  See [Unicode Plural
+/// Rules](https://www.unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax) for the
+/// meaning of each category; not every locale uses all six.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// Options for constructing a [PluralRules].
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    // Left empty for now: cardinal vs. ordinal selection and friends would live here, mirroring
+    // `Intl.PluralRulesOptions`.
+}
+
+/// Implements ECMA-402 `Intl.PluralRules`.
+pub trait PluralRules<L>: Sized
+where
+    L: Locale,
+{
+    type Error;
+
+    /// Constructor method.
+    fn try_new<P: DataProvider>(
+        provider: &P,
+        locale: L,
+        options: Options,
+    ) -> Result<Self, Self::Error>;
+
+    /// Resolves `value` to the plural category appropriate for the locale used to construct
+    /// `self`.
+    fn select(&self, value: f64) -> PluralCategory;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{TestLocale, TestProvider};
+
+    /// A toy English-like rule: `1` is `One`, everything else is `Other`.
+    struct EnglishLikeRules;
+
+    impl PluralRules<TestLocale> for EnglishLikeRules {
+        type Error = ();
+
+        fn try_new<P: DataProvider>(
+            _provider: &P,
+            _locale: TestLocale,
+            _options: Options,
+        ) -> Result<Self, Self::Error> {
+            Ok(EnglishLikeRules)
+        }
+
+        fn select(&self, value: f64) -> PluralCategory {
+            if value == 1.0 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+    }
+
+    #[test]
+    fn selects_category() {
+        let rules =
+            EnglishLikeRules::try_new(&TestProvider, TestLocale, Options::default()).unwrap();
+        assert_eq!(rules.select(1.0), PluralCategory::One);
+        assert_eq!(rules.select(2.0), PluralCategory::Other);
+    }
+}
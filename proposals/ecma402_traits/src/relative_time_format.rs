@@ -0,0 +1,97 @@
+//! Implements ECMA-402 `Intl.RelativeTimeFormat`.
+
+use crate::Locale;
+use crate::data_provider::DataProvider;
+use std::fmt;
+
+/// The unit a relative value is expressed in.  See `Intl.RelativeTimeFormat.format`'s `unit`
+/// parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// Options for constructing a [RelativeTimeFormat].
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    // Left empty for now: numeric ("always" vs. "auto") and style and friends would live here,
+    // mirroring `Intl.RelativeTimeFormatOptions`.
+}
+
+/// Implements ECMA-402 `Intl.RelativeTimeFormat`.
+pub trait RelativeTimeFormat<L>: Sized
+where
+    L: Locale,
+{
+    type Error;
+
+    /// Constructor method.
+    fn try_new<P: DataProvider>(
+        provider: &P,
+        locale: L,
+        options: Options,
+    ) -> Result<Self, Self::Error>;
+
+    /// Formats `value` of `unit`, relative to now, according to the locale and options used to
+    /// construct `self`, writing the result into `sink`.
+    fn format<W: fmt::Write>(&self, value: f64, unit: Unit, sink: &mut W) -> fmt::Result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{TestLocale, TestProvider};
+
+    /// Formats as `"in <value> <unit>"` or `"<value> <unit> ago"`, ignoring locale and options.
+    struct EnglishRelativeTimeFormat;
+
+    impl RelativeTimeFormat<TestLocale> for EnglishRelativeTimeFormat {
+        type Error = ();
+
+        fn try_new<P: DataProvider>(
+            _provider: &P,
+            _locale: TestLocale,
+            _options: Options,
+        ) -> Result<Self, Self::Error> {
+            Ok(EnglishRelativeTimeFormat)
+        }
+
+        fn format<W: fmt::Write>(&self, value: f64, unit: Unit, sink: &mut W) -> fmt::Result {
+            let unit = match unit {
+                Unit::Year => "year",
+                Unit::Quarter => "quarter",
+                Unit::Month => "month",
+                Unit::Week => "week",
+                Unit::Day => "day",
+                Unit::Hour => "hour",
+                Unit::Minute => "minute",
+                Unit::Second => "second",
+            };
+            if value < 0.0 {
+                write!(sink, "{} {unit} ago", -value)
+            } else {
+                write!(sink, "in {value} {unit}")
+            }
+        }
+    }
+
+    #[test]
+    fn formats_future_and_past() {
+        let rtf = EnglishRelativeTimeFormat::try_new(&TestProvider, TestLocale, Options::default())
+            .unwrap();
+        let mut out = String::new();
+        rtf.format(3.0, Unit::Day, &mut out).unwrap();
+        assert_eq!(out, "in 3 day");
+
+        out.clear();
+        rtf.format(-1.0, Unit::Hour, &mut out).unwrap();
+        assert_eq!(out, "1 hour ago");
+    }
+}